@@ -0,0 +1,25 @@
+use std::path::PathBuf;
+
+use miette::Diagnostic;
+use oxc_diagnostics::Error;
+use thiserror::Error;
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Failed to parse config")]
+pub struct FailedToParseConfigError(#[related] pub Vec<Error>);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Failed to parse config {0:?} as {2}: {1}")]
+pub struct FailedToParseConfigJsonError(pub PathBuf, pub String, pub &'static str);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Failed to parse {0} property: {1}")]
+pub struct FailedToParseConfigPropertyError(pub &'static str, pub &'static str);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Detected a cycle while resolving `extends`: {0:?} extends itself, directly or indirectly")]
+pub struct ExtendsCycleError(pub PathBuf);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Failed to parse rule value {0:?}: {1}")]
+pub struct FailedToParseRuleValueError(pub String, pub &'static str);
@@ -1,4 +1,7 @@
-use std::path::PathBuf;
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+};
 
 pub mod errors;
 use oxc_diagnostics::{Error, FailedToOpenFileError, Report};
@@ -11,98 +14,815 @@ use crate::{
 };
 
 use self::errors::{
-    FailedToParseConfigError, FailedToParseConfigJsonError, FailedToParseConfigPropertyError,
-    FailedToParseRuleValueError,
+    ExtendsCycleError, FailedToParseConfigError, FailedToParseConfigJsonError,
+    FailedToParseConfigPropertyError, FailedToParseRuleValueError,
 };
 
+type RuleKey = (String, String);
+type RuleValue = (AllowWarnDeny, Option<Value>);
+
+/// The result of resolving a config file together with everything it (recursively) extends.
+#[derive(Default, Clone)]
+struct ExtendsResolution {
+    /// Plugin names pulled in wholesale via a hardcoded preset in `EXTENDS_MAP`.
+    plugins: HashSet<&'static str>,
+    /// Explicit per-rule decisions, already folded so that later `extends` entries and the
+    /// file's own `rules` block override earlier ones.
+    rules: HashMap<RuleKey, RuleValue>,
+    /// `overrides` blocks, in the file order they were declared in.
+    overrides: Vec<OverrideBlock>,
+}
+
+/// A single entry of an `overrides` array: a set of glob patterns plus the `extends`/`rules`
+/// that apply only to files matching them.
+#[derive(Clone)]
+struct OverrideBlock {
+    /// The directory the config file declaring this block lives in. `files`/`excludedFiles`
+    /// globs are matched against paths relative to this directory, mirroring ESLint.
+    config_dir: PathBuf,
+    files: Vec<glob::Pattern>,
+    excluded_files: Vec<glob::Pattern>,
+    plugins: HashSet<&'static str>,
+    rules: HashMap<RuleKey, RuleValue>,
+}
+
+impl OverrideBlock {
+    fn matches(&self, path: &Path) -> bool {
+        let relative = path.strip_prefix(&self.config_dir).unwrap_or(path);
+        let relative = relative.to_string_lossy();
+        self.files.iter().any(|pattern| pattern.matches(&relative))
+            && !self
+                .excluded_files
+                .iter()
+                .any(|pattern| pattern.matches(&relative))
+    }
+}
+
 pub struct ESLintConfig {
     rules: std::vec::Vec<RuleEnum>,
+    base: ExtendsResolution,
 }
 
 impl ESLintConfig {
     pub fn new(path: &PathBuf) -> Result<Self, Report> {
-        let file = match std::fs::read_to_string(path) {
-            Ok(file) => file,
-            Err(e) => {
-                return Err(FailedToParseConfigError(vec![Error::new(FailedToOpenFileError(
-                    path.clone(),
-                    e,
-                ))])
-                .into());
-            }
-        };
+        let mut visited = HashSet::new();
+        let resolution = resolve_config(path, &mut visited)?;
+        Ok(Self::from_resolution(resolution))
+    }
 
-        let file = match serde_json::from_str::<serde_json::Value>(&file) {
-            Ok(file) => file,
-            Err(e) => {
-                return Err(FailedToParseConfigError(vec![Error::new(
-                    FailedToParseConfigJsonError(path.clone(), e.to_string()),
-                )])
-                .into());
-            }
-        };
+    /// Resolve the effective configuration for `path` by walking up from `path`'s directory
+    /// towards the filesystem root, collecting one `.eslintrc*` per directory (the first of
+    /// `CONFIG_FILE_NAMES` present), and composing them into one configuration.
+    ///
+    /// Layers are folded root-most first, so a directory closer to `path` overrides its
+    /// ancestors, mirroring how ESLint's own config cascade behaves. A layer with `"root": true`
+    /// stops the walk from climbing any further.
+    pub fn resolve_for_path(path: &Path) -> Result<Self, Report> {
+        let start_dir = path.parent().unwrap_or_else(|| Path::new("."));
+        let mut resolution = ExtendsResolution::default();
+
+        for layer_path in collect_cascade_layers(start_dir)? {
+            let mut visited = HashSet::new();
+            let layer = resolve_config(&layer_path, &mut visited)?;
+            // Each layer's `extends` set is unioned in as defaults.
+            resolution.plugins.extend(layer.plugins);
+            // Each layer's explicit rules override the accumulated value.
+            resolution.rules.extend(layer.rules);
+            resolution.overrides.extend(layer.overrides);
+        }
+
+        Ok(Self::from_resolution(resolution))
+    }
 
-        let extends_hm = match parse_extends(&file) {
-            Ok(Some(extends_hm)) => {
-                extends_hm.into_iter().collect::<std::collections::HashSet<_>>()
+    /// The base resolved rules, with every `overrides` block whose globs match `path` re-applied
+    /// on top, in the file order the blocks were declared in.
+    pub fn rules_for(&self, path: &Path) -> Vec<RuleEnum> {
+        let mut resolution = self.base.clone();
+        for block in &self.base.overrides {
+            if block.matches(path) {
+                resolution.plugins.extend(block.plugins.iter().copied());
+                resolution.rules.extend(block.rules.clone());
             }
-            Ok(None) => std::collections::HashSet::new(),
-            Err(e) => {
-                return Err(FailedToParseConfigError(vec![Error::new(
-                    FailedToParseConfigJsonError(path.clone(), e.to_string()),
-                )])
-                .into());
+        }
+
+        let mut rules = compute_rules(&resolution);
+        rules.sort_unstable_by_key(RuleEnum::name);
+        rules
+    }
+
+    /// Serialize the effective configuration for `path` to JSON: for every rule in `RULES`,
+    /// report its plugin, name, final severity, resolved options, and where that decision came
+    /// from (`extends` for a rule enabled only by an `EXTENDS_MAP` preset, `rules` for an
+    /// explicit `rules` entry, including one inherited from a config file reached through
+    /// `extends`, `overrides` for a matching `overrides` block, or the rule's default).
+    pub fn effective_config_report(&self, path: &Path) -> Value {
+        let mut resolution = self.base.clone();
+        let mut provenance: HashMap<RuleKey, Provenance> = resolution
+            .rules
+            .keys()
+            .cloned()
+            .map(|key| (key, Provenance::Rules))
+            .collect();
+
+        for block in &self.base.overrides {
+            if block.matches(path) {
+                resolution.plugins.extend(block.plugins.iter().copied());
+                for (key, value) in &block.rules {
+                    resolution.rules.insert(key.clone(), value.clone());
+                    provenance.insert(key.clone(), Provenance::Overrides);
+                }
             }
-        };
-        let roles_hm = match parse_rules(&file) {
-            Ok(roles_hm) => roles_hm
-                .into_iter()
-                .map(|(plugin_name, rule_name, allow_warn_deny, config)| {
-                    ((plugin_name, rule_name), (allow_warn_deny, config))
+        }
+
+        let rules = RULES
+            .clone()
+            .into_iter()
+            .map(|rule| {
+                let key = (rule.plugin_name().to_string(), rule.name().to_string());
+                let in_extends = resolution.plugins.contains(rule.plugin_name());
+                let explicit = resolution.rules.get(&key);
+
+                let (severity, options) = match explicit {
+                    Some((severity, options)) => (*severity, options.clone()),
+                    None if in_extends => (AllowWarnDeny::Deny, None),
+                    None => (AllowWarnDeny::Allow, None),
+                };
+                let enabled = severity.is_enabled();
+
+                let source = if explicit.is_some() {
+                    provenance.get(&key).copied().unwrap_or(Provenance::Rules)
+                } else if in_extends {
+                    Provenance::Extends
+                } else {
+                    Provenance::Default
+                };
+
+                serde_json::json!({
+                    "plugin": rule.plugin_name(),
+                    "rule": rule.name(),
+                    "enabled": enabled,
+                    "severity": severity_name(severity),
+                    "options": options,
+                    "source": source.as_str(),
                 })
-                .collect::<std::collections::HashMap<_, _>>(),
-            Err(e) => {
-                return Err(e);
-            }
+            })
+            .collect::<Vec<_>>();
+
+        Value::Array(rules)
+    }
+
+    fn from_resolution(resolution: ExtendsResolution) -> Self {
+        let rules = compute_rules(&resolution);
+        Self {
+            rules,
+            base: resolution,
+        }
+    }
+
+    pub fn into_rules(mut self) -> Vec<RuleEnum> {
+        self.rules.sort_unstable_by_key(RuleEnum::name);
+        self.rules
+    }
+}
+
+#[cfg(test)]
+mod effective_config_report_tests {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use super::*;
+
+    fn unique_temp_dir(name: &str) -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let dir =
+            std::env::temp_dir().join(format!("oxc_linter_effective_report_test_{name}_{nanos}"));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    // A rule turned on purely by `extends` (no explicit `rules` entry) must be reported as
+    // `enabled: true` with a severity other than `off` — `enabled` and `severity` must never
+    // contradict each other.
+    #[test]
+    fn extends_only_rules_report_a_matching_severity_and_enabled_flag() {
+        let dir = unique_temp_dir("extends_only");
+        let config_path = dir.join(".eslintrc.json");
+        std::fs::write(&config_path, r#"{ "extends": ["eslint:recommended"] }"#).unwrap();
+
+        let config = ESLintConfig::new(&config_path).unwrap();
+        let report = config.effective_config_report(&dir.join("file.js"));
+        let Value::Array(entries) = report else {
+            panic!("expected an array report");
         };
 
-        // `extends` provides the defaults
-        // `rules` provides the overrides
-        let rules = RULES.clone().into_iter().filter_map(|rule| {
+        for entry in entries {
+            if entry["source"] == "extends" {
+                assert_eq!(entry["enabled"], true);
+                assert_ne!(entry["severity"], "off");
+            }
+        }
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
+
+/// Where a rule's effective severity in [`ESLintConfig::effective_config_report`] came from.
+#[derive(Clone, Copy)]
+enum Provenance {
+    Extends,
+    Rules,
+    Overrides,
+    Default,
+}
+
+impl Provenance {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Extends => "extends",
+            Self::Rules => "rules",
+            Self::Overrides => "overrides",
+            Self::Default => "default",
+        }
+    }
+}
+
+fn severity_name(value: AllowWarnDeny) -> &'static str {
+    match value {
+        AllowWarnDeny::Allow => "off",
+        AllowWarnDeny::Warn => "warn",
+        AllowWarnDeny::Deny => "error",
+    }
+}
+
+/// `extends` provides the defaults, `rules` provides the overrides.
+fn compute_rules(resolution: &ExtendsResolution) -> Vec<RuleEnum> {
+    RULES
+        .clone()
+        .into_iter()
+        .filter_map(|rule| {
             // Check if the extends set is empty or contains the plugin name
-            let in_extends = extends_hm.contains(rule.plugin_name());
+            let in_extends = resolution.plugins.contains(rule.plugin_name());
 
             // Check if there's a custom rule that explicitly handles this rule
-            let (is_explicitly_handled, policy, config) =
-                if let Some((policy, config)) = roles_hm.get(&(rule.plugin_name(), rule.name())) {
-                    // Return true for handling, and also whether it's enabled or not
-                    (true, *policy, config)
-                } else {
-                    // Not explicitly handled
-                    (false, AllowWarnDeny::Allow, &None)
-                };
+            let (is_explicitly_handled, policy, config) = if let Some((policy, config)) = resolution
+                .rules
+                .get(&(rule.plugin_name().to_string(), rule.name().to_string()))
+            {
+                // Return true for handling, and also whether it's enabled or not
+                (true, *policy, config)
+            } else {
+                // Not explicitly handled
+                (false, AllowWarnDeny::Allow, &None)
+            };
 
             // The rule is included if it's in the extends set and not explicitly disabled,
             // or if it's explicitly enabled
             if (in_extends && !is_explicitly_handled) || policy.is_enabled() {
-                Some(rule.read_json(config.cloned()))
+                Some(rule.read_json(config.clone()))
             } else {
                 None
             }
-        });
+        })
+        .collect()
+}
+
+/// The syntax a config file is written in, selected from its extension.
+#[derive(Clone, Copy)]
+enum ConfigFormat {
+    /// `.json`, or no recognized extension at all (e.g. a bare `.eslintrc`). Parsed tolerantly,
+    /// allowing comments and trailing commas like `.jsonc`.
+    Json,
+    Jsonc,
+    Yaml,
+}
 
-        Ok(Self { rules: rules.collect::<Vec<_>>() })
+impl ConfigFormat {
+    fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("jsonc") => Self::Jsonc,
+            Some("yml" | "yaml") => Self::Yaml,
+            _ => Self::Json,
+        }
     }
 
-    pub fn into_rules(mut self) -> Vec<RuleEnum> {
-        self.rules.sort_unstable_by_key(RuleEnum::name);
-        self.rules
+    fn name(self) -> &'static str {
+        match self {
+            Self::Json => "JSON",
+            Self::Jsonc => "JSONC",
+            Self::Yaml => "YAML",
+        }
+    }
+}
+
+fn read_config_json(path: &Path) -> Result<Value, Report> {
+    let file = match std::fs::read_to_string(path) {
+        Ok(file) => file,
+        Err(e) => {
+            return Err(
+                FailedToParseConfigError(vec![Error::new(FailedToOpenFileError(
+                    path.to_path_buf(),
+                    e,
+                ))])
+                .into(),
+            );
+        }
+    };
+
+    let format = ConfigFormat::from_path(path);
+    let parsed = match format {
+        ConfigFormat::Yaml => serde_yaml::from_str::<Value>(&file).map_err(|e| e.to_string()),
+        ConfigFormat::Json | ConfigFormat::Jsonc => {
+            serde_json::from_str::<Value>(&strip_jsonc_comments(&file)).map_err(|e| e.to_string())
+        }
+    };
+
+    match parsed {
+        Ok(value) => Ok(value),
+        Err(message) => Err(FailedToParseConfigError(vec![Error::new(
+            FailedToParseConfigJsonError(path.to_path_buf(), message, format.name()),
+        )])
+        .into()),
+    }
+}
+
+/// Strip `//` and `/* */` comments and trailing commas before an object/array close, so that
+/// JSONC content can be deserialized with a plain JSON parser.
+fn strip_jsonc_comments(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+    let mut in_string = false;
+    let mut escaped = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            out.push(c);
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        match c {
+            '"' => {
+                in_string = true;
+                out.push(c);
+            }
+            '/' if chars.peek() == Some(&'/') => {
+                chars.next();
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        out.push('\n');
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev = '\0';
+                for c in chars.by_ref() {
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            _ => out.push(c),
+        }
+    }
+
+    strip_trailing_commas(&out)
+}
+
+fn strip_trailing_commas(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+    let mut in_string = false;
+    let mut escaped = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            out.push(c);
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        if c == '"' {
+            in_string = true;
+            out.push(c);
+            continue;
+        }
+
+        if c != ',' {
+            out.push(c);
+            continue;
+        }
+
+        let next_significant = chars.clone().find(|c| !c.is_whitespace());
+        if matches!(next_significant, Some('}' | ']')) {
+            // Drop the trailing comma.
+            continue;
+        }
+        out.push(c);
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod strip_trailing_commas_tests {
+    use super::strip_trailing_commas;
+
+    #[test]
+    fn commas_inside_strings_are_left_alone() {
+        let input = r#"{ "pattern": "a,}b,]c" }"#;
+        assert_eq!(strip_trailing_commas(input), input);
+    }
+
+    #[test]
+    fn trailing_commas_before_close_are_still_dropped() {
+        assert_eq!(strip_trailing_commas(r#"{ "a": 1, }"#), r#"{ "a": 1 }"#);
+        assert_eq!(strip_trailing_commas(r#"[1, 2,]"#), r#"[1, 2]"#);
+    }
+}
+
+/// Resolve `path`, recursively following every `extends` entry that isn't one of the hardcoded
+/// presets in `EXTENDS_MAP`, and fold the result into a single [`ExtendsResolution`].
+///
+/// `visited` tracks the canonicalized paths on the current `extends` chain so that a config
+/// which (directly or transitively) extends itself is reported instead of recursing forever.
+fn resolve_config(
+    path: &Path,
+    visited: &mut HashSet<PathBuf>,
+) -> Result<ExtendsResolution, Report> {
+    let canonical = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    if !visited.insert(canonical.clone()) {
+        return Err(
+            FailedToParseConfigError(vec![Error::new(ExtendsCycleError(canonical))]).into(),
+        );
+    }
+
+    let result = (|| -> Result<ExtendsResolution, Report> {
+        let json = read_config_json(path)?;
+
+        let mut resolution = resolve_extends_list(path, parse_extends_entries(&json)?, visited)?;
+
+        // The local `rules` block overrides everything it extends.
+        for (plugin_name, name, allow_warn_deny, config) in parse_rules(&json)? {
+            resolution.rules.insert(
+                (plugin_name.to_string(), name.to_string()),
+                (allow_warn_deny, config.cloned()),
+            );
+        }
+
+        resolution
+            .overrides
+            .extend(parse_overrides(path, &json, visited)?);
+
+        Ok(resolution)
+    })();
+
+    visited.remove(&canonical);
+    result
+}
+
+#[cfg(test)]
+mod resolve_config_tests {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use super::*;
+
+    fn unique_temp_dir(name: &str) -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("oxc_linter_config_test_{name}_{nanos}"));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    // A config that (transitively) extends itself must fail with the canonicalized path of the
+    // file that was re-visited, so the cycle can actually be diagnosed.
+    #[test]
+    fn cycle_error_names_the_offending_path() {
+        let dir = unique_temp_dir("cycle");
+        let a = dir.join("a.json");
+        let b = dir.join("b.json");
+        std::fs::write(&a, r#"{ "extends": ["./b.json"] }"#).unwrap();
+        std::fs::write(&b, r#"{ "extends": ["./a.json"] }"#).unwrap();
+
+        let err = resolve_config(&a, &mut HashSet::new()).unwrap_err();
+        let canonical_a = std::fs::canonicalize(&a).unwrap();
+        let rendered = format!("{err:?}");
+        assert!(rendered.contains(canonical_a.to_string_lossy().as_ref()));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    // `overrides` declared in a file reached via `extends` must be folded into the result, not
+    // dropped on the floor.
+    #[test]
+    fn overrides_from_an_extended_config_are_kept() {
+        let dir = unique_temp_dir("extends_overrides");
+        let base = dir.join("base.json");
+        let child = dir.join("child.json");
+        std::fs::write(
+            &base,
+            r#"{ "overrides": [{ "files": ["*.test.js"], "rules": { "eslint/no-console": "error" } }] }"#,
+        )
+        .unwrap();
+        std::fs::write(&child, r#"{ "extends": ["./base.json"] }"#).unwrap();
+
+        let resolution = resolve_config(&child, &mut HashSet::new()).unwrap();
+        assert_eq!(resolution.overrides.len(), 1);
+        assert!(resolution.overrides[0].matches(&dir.join("foo.test.js")));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
+
+/// Fold a config's `extends` entries into an [`ExtendsResolution`], recursively resolving any
+/// local/shareable references relative to `path`.
+fn resolve_extends_list(
+    path: &Path,
+    entries: Vec<ExtendsEntry>,
+    visited: &mut HashSet<PathBuf>,
+) -> Result<ExtendsResolution, Report> {
+    let mut resolution = ExtendsResolution::default();
+
+    for entry in entries {
+        match entry {
+            ExtendsEntry::Preset(plugin) => {
+                resolution.plugins.insert(plugin);
+            }
+            ExtendsEntry::Reference(specifier) => {
+                let extended_path = resolve_extends_path(path, &specifier);
+                let extended = resolve_config(&extended_path, visited)?;
+                resolution.plugins.extend(extended.plugins);
+                // Later `extends` entries override earlier ones.
+                resolution.rules.extend(extended.rules);
+                resolution.overrides.extend(extended.overrides);
+            }
+        }
+    }
+
+    Ok(resolution)
+}
+
+fn parse_overrides(
+    path: &Path,
+    root_json: &Value,
+    visited: &mut HashSet<PathBuf>,
+) -> Result<Vec<OverrideBlock>, Report> {
+    let Some(Value::Array(overrides)) = root_json.get("overrides") else {
+        return Ok(vec![]);
+    };
+
+    overrides
+        .iter()
+        .map(|entry| parse_override_block(path, entry, visited))
+        .collect()
+}
+
+fn parse_override_block(
+    path: &Path,
+    entry: &Value,
+    visited: &mut HashSet<PathBuf>,
+) -> Result<OverrideBlock, Report> {
+    let files = parse_glob_list(entry, "files")?;
+    if files.is_empty() {
+        return Err(FailedToParseConfigPropertyError(
+            "overrides",
+            "Expected a non-empty `files` list.",
+        )
+        .into());
+    }
+    let excluded_files = parse_glob_list(entry, "excludedFiles")?;
+
+    let mut resolution = resolve_extends_list(path, parse_extends_entries(entry)?, visited)?;
+    for (plugin_name, name, allow_warn_deny, config) in parse_rules(entry)? {
+        resolution.rules.insert(
+            (plugin_name.to_string(), name.to_string()),
+            (allow_warn_deny, config.cloned()),
+        );
+    }
+
+    let config_dir = path
+        .parent()
+        .unwrap_or_else(|| Path::new("."))
+        .to_path_buf();
+
+    Ok(OverrideBlock {
+        config_dir,
+        files,
+        excluded_files,
+        plugins: resolution.plugins,
+        rules: resolution.rules,
+    })
+}
+
+#[cfg(test)]
+mod override_block_tests {
+    use std::{
+        collections::{HashMap, HashSet},
+        path::{Path, PathBuf},
+    };
+
+    use super::OverrideBlock;
+
+    #[test]
+    fn matches_against_path_relative_to_config_dir() {
+        let block = OverrideBlock {
+            config_dir: PathBuf::from("/project/packages/app"),
+            files: vec![glob::Pattern::new("src/*.ts").unwrap()],
+            excluded_files: vec![],
+            plugins: HashSet::new(),
+            rules: HashMap::new(),
+        };
+
+        assert!(block.matches(Path::new("/project/packages/app/src/index.ts")));
+        assert!(!block.matches(Path::new("/project/packages/other/src/index.ts")));
+    }
+}
+
+fn parse_glob_list(value: &Value, key: &'static str) -> Result<Vec<glob::Pattern>, Report> {
+    let Some(value) = value.get(key) else {
+        return Ok(vec![]);
+    };
+
+    let patterns = match value {
+        Value::String(s) => vec![s.clone()],
+        Value::Array(v) => v
+            .iter()
+            .filter_map(|v| v.as_str().map(str::to_string))
+            .collect(),
+        _ => {
+            return Err(FailedToParseConfigPropertyError(
+                key,
+                "Expected a string or array of strings.",
+            )
+            .into());
+        }
+    };
+
+    patterns
+        .iter()
+        .map(|pattern| {
+            glob::Pattern::new(pattern)
+                .map_err(|_| FailedToParseConfigPropertyError(key, "Invalid glob pattern.").into())
+        })
+        .collect()
+}
+
+/// Candidate config file names, in the order ESLint itself prefers them within a directory.
+const CONFIG_FILE_NAMES: &[&str] = &[
+    ".eslintrc",
+    ".eslintrc.json",
+    ".eslintrc.jsonc",
+    ".eslintrc.yml",
+    ".eslintrc.yaml",
+];
+
+fn find_config_file(dir: &Path) -> Option<PathBuf> {
+    CONFIG_FILE_NAMES
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|candidate| candidate.is_file())
+}
+
+fn config_is_root(path: &Path) -> Result<bool, Report> {
+    let json = read_config_json(path)?;
+    Ok(json.get("root").and_then(Value::as_bool).unwrap_or(false))
+}
+
+/// Walk from `start_dir` up through its ancestors collecting every config file found, stopping
+/// as soon as a `"root": true` layer is reached. Returns the layers root-most first, ready to be
+/// folded left-to-right.
+fn collect_cascade_layers(start_dir: &Path) -> Result<Vec<PathBuf>, Report> {
+    let mut layers = vec![];
+    let mut current = Some(start_dir);
+
+    while let Some(dir) = current {
+        if let Some(config_path) = find_config_file(dir) {
+            let is_root = config_is_root(&config_path)?;
+            layers.push(config_path);
+            if is_root {
+                break;
+            }
+        }
+        current = dir.parent();
+    }
+
+    layers.reverse();
+    Ok(layers)
+}
+
+#[cfg(test)]
+mod cascade_tests {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use super::*;
+
+    fn unique_temp_dir(name: &str) -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("oxc_linter_cascade_test_{name}_{nanos}"));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    // `collect_cascade_layers` must climb from `start_dir` up to a `"root": true` config,
+    // include that layer, and stop there, returning the layers root-most first.
+    #[test]
+    fn collects_two_layers_and_stops_at_root() {
+        let root_dir = unique_temp_dir("root_stop");
+        let child_dir = root_dir.join("child");
+        std::fs::create_dir_all(&child_dir).unwrap();
+
+        let root_config = root_dir.join(".eslintrc.json");
+        let child_config = child_dir.join(".eslintrc.json");
+        std::fs::write(&root_config, r#"{ "root": true, "rules": {} }"#).unwrap();
+        std::fs::write(&child_config, r#"{ "rules": {} }"#).unwrap();
+
+        let layers = collect_cascade_layers(&child_dir).unwrap();
+        assert_eq!(layers, vec![root_config, child_config]);
+
+        std::fs::remove_dir_all(&root_dir).ok();
+    }
+
+    // A config file above the `"root": true` layer must never be picked up.
+    #[test]
+    fn does_not_climb_past_a_root_layer() {
+        let outer_dir = unique_temp_dir("no_climb");
+        let root_dir = outer_dir.join("project");
+        let child_dir = root_dir.join("child");
+        std::fs::create_dir_all(&child_dir).unwrap();
+
+        let outer_config = outer_dir.join(".eslintrc.json");
+        let root_config = root_dir.join(".eslintrc.json");
+        std::fs::write(&outer_config, r#"{ "rules": {} }"#).unwrap();
+        std::fs::write(&root_config, r#"{ "root": true, "rules": {} }"#).unwrap();
+
+        let layers = collect_cascade_layers(&child_dir).unwrap();
+        assert_eq!(layers, vec![root_config]);
+
+        std::fs::remove_dir_all(&outer_dir).ok();
+    }
+
+    // `resolve_for_path` folds layers root-most first, so a rule set by the root layer and
+    // overridden by the child layer must end up at the child's severity.
+    #[test]
+    fn resolve_for_path_lets_child_layer_override_root_layer() {
+        let root_dir = unique_temp_dir("resolve_for_path");
+        let child_dir = root_dir.join("child");
+        std::fs::create_dir_all(&child_dir).unwrap();
+
+        std::fs::write(
+            root_dir.join(".eslintrc.json"),
+            r#"{ "root": true, "rules": { "eslint/no-console": "warn" } }"#,
+        )
+        .unwrap();
+        std::fs::write(
+            child_dir.join(".eslintrc.json"),
+            r#"{ "rules": { "eslint/no-console": "error" } }"#,
+        )
+        .unwrap();
+
+        let config = ESLintConfig::resolve_for_path(&child_dir.join("file.js")).unwrap();
+        let key = ("eslint".to_string(), "no-console".to_string());
+        assert_eq!(
+            config.base.rules.get(&key),
+            Some(&(AllowWarnDeny::Deny, None))
+        );
+
+        std::fs::remove_dir_all(&root_dir).ok();
     }
 }
 
-fn parse_extends(root_json: &Value) -> Result<Option<Vec<&'static str>>, Report> {
+enum ExtendsEntry {
+    /// One of the hardcoded presets in `EXTENDS_MAP`, e.g. `"eslint:recommended"`.
+    Preset(&'static str),
+    /// A local file or shareable config package that must be resolved and parsed recursively.
+    Reference(String),
+}
+
+fn parse_extends_entries(root_json: &Value) -> Result<Vec<ExtendsEntry>, Report> {
     let Some(extends) = root_json.get("extends") else {
-        return Ok(None);
+        return Ok(vec![]);
     };
 
     let extends_obj = match extends {
@@ -112,32 +832,69 @@ fn parse_extends(root_json: &Value) -> Result<Option<Vec<&'static str>>, Report>
         }
     };
 
-    let extends_rule_groups = extends_obj
+    let entries = extends_obj
         .iter()
         .filter_map(|v| {
-            let v = match v {
-                Value::String(s) => s,
-                _ => return None,
-            };
+            let v = v.as_str()?;
 
-            if let Some(m) = EXTENDS_MAP.get(v.as_str()) {
-                return Some(*m);
+            if let Some(m) = EXTENDS_MAP.get(v) {
+                return Some(ExtendsEntry::Preset(*m));
             }
 
-            None
+            Some(ExtendsEntry::Reference(v.to_string()))
         })
         .collect::<Vec<_>>();
 
-    Ok(Some(extends_rule_groups))
+    Ok(entries)
+}
+
+/// Resolve an `extends` entry that isn't a hardcoded preset to a path on disk.
+///
+/// Paths starting with `.` or `/` are resolved relative to the directory of the config file
+/// that references them. Anything else is treated as a shareable config package and looked up
+/// under `node_modules`, mirroring ESLint's own resolution order.
+fn resolve_extends_path(current_config: &Path, specifier: &str) -> PathBuf {
+    let base_dir = current_config.parent().unwrap_or_else(|| Path::new("."));
+
+    if specifier.starts_with('.') || specifier.starts_with('/') {
+        base_dir.join(specifier)
+    } else {
+        base_dir
+            .join("node_modules")
+            .join(shareable_config_package_name(specifier))
+            .join("index.json")
+    }
+}
+
+/// Map a shareable-config `extends` specifier to the npm package ESLint would resolve it to:
+/// `"airbnb"` -> `"eslint-config-airbnb"`, `"@scope/foo"` -> `"@scope/eslint-config-foo"`, and a
+/// specifier that is already a full package name (scoped or not) is used as-is.
+fn shareable_config_package_name(specifier: &str) -> String {
+    if let Some(scoped) = specifier.strip_prefix('@') {
+        let (scope, name) = scoped.split_once('/').unwrap_or((scoped, ""));
+        if name.starts_with("eslint-config") {
+            specifier.to_string()
+        } else {
+            format!("@{scope}/eslint-config-{name}")
+        }
+    } else if specifier.starts_with("eslint-config-") {
+        specifier.to_string()
+    } else {
+        format!("eslint-config-{specifier}")
+    }
 }
 
 #[allow(clippy::type_complexity)]
 fn parse_rules(
     root_json: &Value,
 ) -> Result<Vec<(&str, &str, AllowWarnDeny, Option<&Value>)>, Error> {
-    let Value::Object(rules_object) = root_json else { return Ok(vec![]) };
+    let Value::Object(rules_object) = root_json else {
+        return Ok(vec![]);
+    };
 
-    let Some(Value::Object(rules_object)) = rules_object.get("rules") else { return Ok(vec![]) };
+    let Some(Value::Object(rules_object)) = rules_object.get("rules") else {
+        return Ok(vec![]);
+    };
 
     rules_object
         .iter()
@@ -146,6 +903,8 @@ fn parse_rules(
 
             let (rule_severity, rule_config) = resolve_rule_value(value)?;
 
+            validate_rule_value(plugin_name, name, rule_config)?;
+
             Ok((plugin_name, name, rule_severity, rule_config))
         })
         .collect::<Result<Vec<_>, Error>>()
@@ -186,15 +945,126 @@ fn parse_rule_name(name: &str) -> (&str, &str) {
 /// }
 /// ```
 fn resolve_rule_value(value: &serde_json::Value) -> Result<(AllowWarnDeny, Option<&Value>), Error> {
-    if let Some(v) = value.as_str() {
-        return Ok((AllowWarnDeny::try_from(v)?, None));
+    if value.is_string() || value.is_number() {
+        return Ok((parse_severity(value)?, None));
     }
 
     if let Some(v) = value.as_array() {
         if let Some(v_idx_0) = v.get(0) {
-            return Ok((AllowWarnDeny::try_from(v_idx_0)?, v.get(1)));
+            return Ok((parse_severity(v_idx_0)?, v.get(1)));
         }
     }
 
     Err(FailedToParseRuleValueError(value.to_string(), "Invalid rule value").into())
 }
+
+/// Parse a rule severity, accepting both ESLint's string levels (`"off"`/`"warn"`/`"error"`) and
+/// its numeric levels (`0`/`1`/`2`).
+fn parse_severity(value: &Value) -> Result<AllowWarnDeny, Error> {
+    if let Some(s) = value.as_str() {
+        return Ok(AllowWarnDeny::try_from(s)?);
+    }
+
+    if let Some(n) = value.as_i64() {
+        return match n {
+            0 => Ok(AllowWarnDeny::Allow),
+            1 => Ok(AllowWarnDeny::Warn),
+            2 => Ok(AllowWarnDeny::Deny),
+            _ => Err(FailedToParseRuleValueError(
+                value.to_string(),
+                "Expected a severity of 0, 1, or 2.",
+            )
+            .into()),
+        };
+    }
+
+    Err(FailedToParseRuleValueError(value.to_string(), "Expected an integer severity.").into())
+}
+
+#[cfg(test)]
+mod parse_severity_tests {
+    use super::*;
+
+    #[test]
+    fn accepts_the_three_numeric_severities() {
+        assert!(matches!(
+            parse_severity(&Value::from(0)).unwrap(),
+            AllowWarnDeny::Allow
+        ));
+        assert!(matches!(
+            parse_severity(&Value::from(1)).unwrap(),
+            AllowWarnDeny::Warn
+        ));
+        assert!(matches!(
+            parse_severity(&Value::from(2)).unwrap(),
+            AllowWarnDeny::Deny
+        ));
+    }
+
+    #[test]
+    fn rejects_an_out_of_range_numeric_severity() {
+        assert!(parse_severity(&Value::from(3)).is_err());
+    }
+
+    #[test]
+    fn still_accepts_the_string_severities() {
+        assert!(matches!(
+            parse_severity(&Value::from("error")).unwrap(),
+            AllowWarnDeny::Deny
+        ));
+    }
+}
+
+/// Validate a rule's options against its JSON Schema (`RuleEnum::schema`), so a malformed
+/// `rules` entry is rejected here instead of being silently mis-read by `RuleEnum::read_json`.
+///
+/// Absence of options is always valid — ESLint only validates what was actually supplied, so a
+/// bare `"rule-name": "error"` must not be checked against a schema that expects an array/object.
+fn validate_rule_value(plugin_name: &str, name: &str, config: Option<&Value>) -> Result<(), Error> {
+    let Some(instance) = config else {
+        return Ok(());
+    };
+
+    let Some(rule) = RULES
+        .iter()
+        .find(|rule| rule.plugin_name() == plugin_name && rule.name() == name)
+    else {
+        // Unknown rule names are simply never enabled; nothing to validate.
+        return Ok(());
+    };
+
+    let Some(schema) = rule.schema() else {
+        return Ok(());
+    };
+
+    let compiled = jsonschema::JSONSchema::compile(schema).map_err(|e| {
+        FailedToParseRuleValueError(
+            format!("{name}: invalid schema ({e})"),
+            "Invalid rule schema",
+        )
+    })?;
+
+    if let Err(mut errors) = compiled.validate(instance) {
+        if let Some(error) = errors.next() {
+            return Err(FailedToParseRuleValueError(
+                format!("{name}: {} at {}", error, error.instance_path),
+                "Rule options do not match the rule's schema",
+            )
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod validate_rule_value_tests {
+    use super::validate_rule_value;
+
+    // A bare `"rule-name": "error"` (no options) must never be rejected, even for a rule whose
+    // schema requires an array/object — omitted options are always valid in ESLint.
+    #[test]
+    fn none_config_always_skips_schema_validation() {
+        assert!(validate_rule_value("eslint", "no-unused-vars", None).is_ok());
+    }
+}